@@ -0,0 +1,107 @@
+//! Bridges the six hex neighbor directions to real-world compass angles,
+//! so units placed on the map can have a facing and turn toward a
+//! target hex.
+
+use std::f32::consts::PI;
+
+use crate::map::Orientation;
+use crate::point::{Point, ORIGIN, UNIT};
+
+const SIXTH_TURN: f32 = PI / 3.0;
+
+/// One of the six hex neighbor directions, indexing into `UNIT`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Direction(u8);
+
+impl Direction {
+    pub fn new(index: u8) -> Direction {
+	Direction(index % 6)
+    }
+
+    pub fn index(&self) -> usize {
+	self.0 as usize
+    }
+
+    /// The unit vector this direction points along.
+    pub fn to_point(&self) -> Point {
+	UNIT[self.index()]
+    }
+
+    /// The direction a unit vector points in, if it is one of the six in
+    /// `UNIT`.
+    pub fn from_point(p: &Point) -> Option<Direction> {
+	UNIT.iter().position(|unit| unit == p).map(|i| Direction::new(i as u8))
+    }
+
+    pub fn opposite(&self) -> Direction {
+	Direction::new(self.0 + 3)
+    }
+
+    pub fn rotate_cw(&self, n: u8) -> Direction {
+	Direction::new(self.0 + n)
+    }
+
+    pub fn rotate_ccw(&self, n: u8) -> Direction {
+	Direction::new(self.0 + 6 - (n % 6))
+    }
+
+    /// The compass angle this direction points toward, in radians, for a
+    /// hex grid laid out with the given orientation: pointy-top
+    /// (`Vertical`) directions start at 30° and step 60°, flat-top
+    /// (`Horizontal`) directions start at 0°.
+    pub fn to_radians(&self, orientation: Orientation) -> f32 {
+	start_angle(orientation) + self.index() as f32 * SIXTH_TURN
+    }
+
+    /// The direction whose compass angle, in the given orientation, is
+    /// closest to `radians`.
+    pub fn from_radians(radians: f32, orientation: Orientation) -> Direction {
+	let steps = (radians - start_angle(orientation)) / SIXTH_TURN;
+	Direction::new(steps.round().rem_euclid(6.0) as u8)
+    }
+}
+
+fn start_angle(orientation: Orientation) -> f32 {
+    match orientation {
+	Orientation::Vertical => PI / 6.0,
+	Orientation::Horizontal => 0.0
+    }
+}
+
+/// The smaller of the two ways around the circle between two angles, in
+/// radians.
+fn angular_distance(a: f32, b: f32) -> f32 {
+    let diff = (a - b).rem_euclid(2.0 * PI);
+    diff.min(2.0 * PI - diff)
+}
+
+impl Point {
+    /// The angle, in radians, of the vector from `self` to `other`,
+    /// taken as `atan2(hy, hx)` of its axial components. This is not the
+    /// compass angle that `Direction::to_radians` gives.
+    pub fn angle_to(&self, other: &Point) -> f32 {
+	let diff = *other - *self;
+	(diff.hy as f32).atan2(diff.hx as f32)
+    }
+
+    /// The one of the six unit directions that points most toward
+    /// `other`.
+    ///
+    /// Compares `angle_to` against that same measure applied to each
+    /// unit vector (rather than `Direction::to_radians`, which lives in
+    /// an unrelated compass-angle space) so "nearest" is judged on a
+    /// single, consistent angle.
+    pub fn direction_to(&self, other: &Point) -> Direction {
+	let angle = self.angle_to(other);
+
+	(0..6u8)
+	    .min_by(|&a, &b| {
+		let da = angular_distance(angle, ORIGIN.angle_to(&UNIT[a as usize]));
+		let db = angular_distance(angle, ORIGIN.angle_to(&UNIT[b as usize]));
+		da.partial_cmp(&db).unwrap()
+	    })
+	    .map(Direction::new)
+	    .unwrap()
+    }
+}
@@ -0,0 +1,71 @@
+//
+//
+//
+use std::collections::HashMap;
+
+use crate::hex::Hex;
+use crate::point::{Point, UNIT};
+
+/// A map storage backend keyed by hex `Point`. `HashGrid` is its only
+/// implementation; the dense `Row` storage of `map::Map` does not
+/// implement it.
+pub trait Grid {
+    fn get(&self, p: &Point) -> Option<&Hex>;
+    fn insert(&mut self, p: Point, h: Hex);
+    fn len(&self) -> usize;
+    fn is_empty(&self) -> bool;
+}
+
+/// Sparse, hash-backed map storage keyed by hex `Point`.
+///
+/// Unlike the dense `Vec<Row>` storage in `map.rs`, a `HashGrid` only
+/// pays for the hexes it actually holds, so it can represent
+/// non-rectangular shapes and worlds too large, or unbounded, to lay out
+/// as a rectangle.
+pub struct HashGrid {
+    fields: HashMap<Point, Hex>
+}
+
+impl HashGrid {
+    pub fn new() -> HashGrid {
+	HashGrid { fields: HashMap::new() }
+    }
+
+    /// The hexes adjacent to `p`, skipping any of the six neighbor
+    /// positions that aren't present in the grid.
+    pub fn neighbors(&self, p: &Point) -> Vec<&Hex> {
+	UNIT.iter()
+	    .filter_map(|unit| self.get(&(*p + *unit)))
+	    .collect()
+    }
+}
+
+impl Grid for HashGrid {
+    fn get(&self, p: &Point) -> Option<&Hex> {
+	self.fields.get(p)
+    }
+
+    fn insert(&mut self, p: Point, h: Hex) {
+	self.fields.insert(p, h);
+    }
+
+    fn len(&self) -> usize {
+	self.fields.len()
+    }
+
+    fn is_empty(&self) -> bool {
+	self.fields.is_empty()
+    }
+}
+
+impl Default for HashGrid {
+    fn default() -> HashGrid {
+	HashGrid::new()
+    }
+}
+
+impl FromIterator<(Point, Hex)> for HashGrid {
+    fn from_iter<I: IntoIterator<Item = (Point, Hex)>>(iter: I) -> HashGrid {
+	HashGrid { fields: HashMap::from_iter(iter) }
+    }
+}
@@ -1,18 +1,32 @@
 //
 //
 //
+use geo::geometry::{Coord, LineString, Polygon};
+
+use crate::layout::Layout;
 use crate::point::Point;
 
+#[derive(Debug, PartialEq)]
 pub struct Hex {
     location: Point,
 //    terrain: Vec<Terrain>,
 //    contents: mut Vec<Item>,
-//    occupants: mut Vec<Unit>	
+//    occupants: mut Vec<Unit>
 }
 
 impl Hex {
     pub fn new(location: Point) -> Hex {
-	Hex { location: location }
+	Hex { location }
+    }
+
+    /// Build a `geo::Polygon` outlining this hex in pixel space, so it
+    /// can be rendered or handed off to other GIS tooling.
+    pub fn to_polygon(&self, layout: &Layout) -> Polygon<f32> {
+	let corners = layout.polygon_corners(&self.location);
+	let mut ring: Vec<Coord<f32>> = corners.to_vec();
+	ring.push(corners[0]);
+
+	Polygon::new(LineString::new(ring), vec![])
     }
 }
 #[cfg(test)]
@@ -0,0 +1,98 @@
+//! Converts between hex `Point` coordinates and pixel space so a map can
+//! be drawn in a window and mouse clicks can be mapped back to a hex.
+//!
+//! The algorithms are derived from the wonderful page by Redblob Games:
+//! [Hexagonal Grids](https://www.redblobgames.com/grids/hexagons/)
+
+use std::f32::consts::PI;
+
+use geo::geometry::Coord;
+
+use crate::map::Orientation;
+use crate::point::Point;
+
+const SQRT_3: f32 = 1.7320508;
+
+/// A `Layout` reuses `map::Orientation` to pick the hex shape: `Vertical`
+/// lays hexes out pointy-top (a corner points straight up), `Horizontal`
+/// lays them out flat-top (a flat edge on top).
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Layout {
+    pub orientation: Orientation,
+    pub size: Coord<f32>,
+    pub origin: Coord<f32>
+}
+
+impl Layout {
+
+    pub fn new(orientation: Orientation, size: Coord<f32>, origin: Coord<f32>) -> Layout {
+	Layout { orientation, size, origin }
+    }
+
+    /// Find the pixel position of the center of a hex
+    pub fn hex_to_pixel(&self, p: &Point) -> Coord<f32> {
+	let fp = p.to_f32_point();
+	let (hx, hy) = (fp.hx, fp.hy);
+
+	let (x, y) = match self.orientation {
+	    Orientation::Vertical => (
+		SQRT_3 * hx + SQRT_3 / 2.0 * hy,
+		3.0 / 2.0 * hy
+	    ),
+	    Orientation::Horizontal => (
+		3.0 / 2.0 * hx,
+		SQRT_3 / 2.0 * hx + SQRT_3 * hy
+	    )
+	};
+
+	Coord { x: x * self.size.x + self.origin.x, y: y * self.size.y + self.origin.y }
+    }
+
+    /// Find the hex that contains a pixel position.
+    ///
+    /// Inverts the orientation matrix to get fractional axial
+    /// coordinates, then snaps to the nearest hex via cube rounding
+    /// (rounding `hx`/`hy` independently can land just off the true hex).
+    pub fn pixel_to_hex(&self, pixel: Coord<f32>) -> Point {
+	let px = (pixel.x - self.origin.x) / self.size.x;
+	let py = (pixel.y - self.origin.y) / self.size.y;
+
+	let (hx, hy) = match self.orientation {
+	    Orientation::Vertical => (
+		SQRT_3 / 3.0 * px - 1.0 / 3.0 * py,
+		2.0 / 3.0 * py
+	    ),
+	    Orientation::Horizontal => (
+		2.0 / 3.0 * px,
+		-1.0 / 3.0 * px + SQRT_3 / 3.0 * py
+	    )
+	};
+
+	Point::<f32> { hx, hy }.round()
+    }
+
+    /// The offset of one of the six corners of a hex from its center,
+    /// going clockwise starting at the first corner.
+    fn corner_offset(&self, corner: usize) -> Coord<f32> {
+	let start_angle = match self.orientation {
+	    Orientation::Vertical => 0.5,
+	    Orientation::Horizontal => 0.0
+	};
+	let angle = 2.0 * PI * (start_angle + corner as f32) / 6.0;
+
+	Coord { x: self.size.x * angle.cos(), y: self.size.y * angle.sin() }
+    }
+
+    /// The six vertex positions of a hex, for rendering its outline.
+    pub fn polygon_corners(&self, p: &Point) -> [Coord<f32>; 6] {
+	let center = self.hex_to_pixel(p);
+	let mut corners = [Coord { x: 0.0, y: 0.0 }; 6];
+
+	for (corner, slot) in corners.iter_mut().enumerate() {
+	    let offset = self.corner_offset(corner);
+	    *slot = Coord { x: center.x + offset.x, y: center.y + offset.y };
+	}
+
+	corners
+    }
+}
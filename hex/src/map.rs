@@ -3,8 +3,11 @@
 //
 use std::ops::Index;
 
-use crate::point::{Point,ORIGIN};
+use geo::geometry::{LineString, MultiPolygon, Polygon};
+
+use crate::point::Point;
 use crate::hex::Hex;
+use crate::layout::Layout;
 
 
 pub enum Shape {
@@ -13,6 +16,8 @@ pub enum Shape {
     MegaHex
 }
 
+#[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Orientation {
     Horizontal,
     Vertical
@@ -23,15 +28,21 @@ pub struct Row {
 }
 
 impl Row {
-    fn new() -> Row {
+    pub fn new() -> Row {
 	Row { row: vec!() }
     }
 
-    fn push(&mut self, h: Hex) {
+    pub fn push(&mut self, h: Hex) {
 	self.row.push(h);
     }
 }
 
+impl Default for Row {
+    fn default() -> Row {
+	Row::new()
+    }
+}
+
 impl Index<usize> for Row {
     type Output = Hex;
 
@@ -51,7 +62,7 @@ pub struct Map {
 
 impl Map {
     
-    fn new(shape: Shape, orientation: Orientation, size: Point, origin: Point) -> Map {
+    pub fn new(shape: Shape, orientation: Orientation, size: Point, origin: Point) -> Map {
 	let mut rows: Vec<Row> = vec!();
 
 	for i in 0..size.hx {
@@ -60,9 +71,42 @@ impl Map {
 		rows[i as usize].push(Hex::new (Point { hx: i + origin.hx, hy: j + origin.hy }));
 	    }
 	}
-	
-	Map { shape: shape, orientation: orientation, size: size, origin: origin, rows: rows }
+
+	Map { shape, orientation, size, origin, rows }
+    }
+
+    /// Collect every cell into a single `geo::MultiPolygon`, in pixel
+    /// space, so the whole map can be rendered or exported at once.
+    pub fn to_multipolygon(&self, layout: &Layout) -> MultiPolygon<f32> {
+	let polygons: Vec<Polygon<f32>> = self.rows.iter()
+	    .flat_map(|row| row.row.iter())
+	    .map(|hex| hex.to_polygon(layout))
+	    .collect();
+
+	MultiPolygon::new(polygons)
     }
+
+    /// Serialize this map to Well-Known Text, so it can be dumped
+    /// straight into PostGIS, QGIS, or any other GIS toolchain.
+    pub fn to_wkt(&self, layout: &Layout) -> String {
+	multipolygon_to_wkt(&self.to_multipolygon(layout))
+    }
+}
+
+fn ring_to_wkt(ring: &LineString<f32>) -> String {
+    ring.coords()
+	.map(|c| format!("{} {}", c.x, c.y))
+	.collect::<Vec<String>>()
+	.join(", ")
+}
+
+fn polygon_to_wkt(polygon: &Polygon<f32>) -> String {
+    format!("(({}))", ring_to_wkt(polygon.exterior()))
+}
+
+fn multipolygon_to_wkt(multipolygon: &MultiPolygon<f32>) -> String {
+    let polygons: Vec<String> = multipolygon.iter().map(polygon_to_wkt).collect();
+    format!("MULTIPOLYGON ({})", polygons.join(", "))
 }
 
 impl Index<usize> for Map {
@@ -78,7 +122,7 @@ impl Index<usize> for Map {
 
 #[test]
 fn test_map_new() {
-    let m0 = Map::new(Shape::Rectangle, Orientation::Vertical, Point { hx: 5, hy: 6 }, ORIGIN);
+    let m0 = Map::new(Shape::Rectangle, Orientation::Vertical, Point { hx: 5, hy: 6 }, crate::point::ORIGIN);
 
     assert_eq!(m0.size.hx, 5);
 
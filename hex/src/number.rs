@@ -0,0 +1,54 @@
+//! A coordinate type `Point` can be built from: the usual arithmetic
+//! plus the conversions needed to bounce between integer hex grids and
+//! fractional (pixel / line-interpolation) math without a lossy
+//! intermediate type.
+
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+pub trait Number:
+    Copy
+    + PartialEq
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    fn from_f32(v: f32) -> Self;
+    fn to_f32(self) -> f32;
+    fn from_isize(v: isize) -> Self;
+    fn to_isize(self) -> isize;
+    fn abs(self) -> Self;
+}
+
+macro_rules! impl_number {
+    ($t:ty) => {
+	impl Number for $t {
+	    fn from_f32(v: f32) -> Self {
+		v as $t
+	    }
+
+	    fn to_f32(self) -> f32 {
+		self as f32
+	    }
+
+	    fn from_isize(v: isize) -> Self {
+		v as $t
+	    }
+
+	    fn to_isize(self) -> isize {
+		self as isize
+	    }
+
+	    fn abs(self) -> Self {
+		<$t>::abs(self)
+	    }
+	}
+    };
+}
+
+impl_number!(i32);
+impl_number!(i64);
+impl_number!(f32);
+impl_number!(f64);
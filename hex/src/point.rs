@@ -1,31 +1,42 @@
-/// This class defines Hex Point and the algebra for manipulating them
-///
-/// The algorithms are derived from the wonderful page by Redblob Games:
-/// [Hexagonal Grids](https://www.redblobgames.com/grids/hexagons/)
-///
-/// Mathematically a hex map is a
-/// [triangular lattice](https://en.wikipedia.org/wiki/Hexagonal_lattice).
-/// Each node is directly connected to six other nodes in pairs on
-/// three axes. A single node can be defined by its distance from the
-/// origin on two axes. The location on the third axis is dependent
-/// and can be derived from the other two values.
-
-/// This model uses a slightly modified version of the
-/// [Axial](https://www.redblobgames.com/grids/hexagons/#coordinates-axial)
-/// coordinate system. All of the possible coordinate systems are equivalent
-/// and so can be converted from one to the other if needed. 
+//! This class defines Hex Point and the algebra for manipulating them
+//!
+//! The algorithms are derived from the wonderful page by Redblob Games:
+//! [Hexagonal Grids](https://www.redblobgames.com/grids/hexagons/)
+//!
+//! Mathematically a hex map is a
+//! [triangular lattice](https://en.wikipedia.org/wiki/Hexagonal_lattice).
+//! Each node is directly connected to six other nodes in pairs on
+//! three axes. A single node can be defined by its distance from the
+//! origin on two axes. The location on the third axis is dependent
+//! and can be derived from the other two values.
+//!
+//! This model uses a slightly modified version of the
+//! [Axial](https://www.redblobgames.com/grids/hexagons/#coordinates-axial)
+//! coordinate system. All of the possible coordinate systems are equivalent
+//! and so can be converted from one to the other if needed.
 
-use std::ops::{Add, Sub, Mul};
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
 
 // this pulled in a LOT of stuff and could be a tuple of f32 for one internal call.
 // But Learning
 use geo::geometry::Coord;
+use geo::CoordNum;
 
-/// Each hex location is defined by two integers, hx and hy
-#[derive(Clone, Copy, Debug, PartialEq)] 
-pub struct Point {
-    pub hx: i32,
-    pub hy: i32
+use crate::direction::Direction;
+use crate::number::Number;
+
+/// Each hex location is defined by two coordinates, hx and hy. `T` is
+/// `i32` by default for grid logic, but can be `f32` (or any other
+/// `Number`) for fractional math such as line interpolation or
+/// pixel conversion, without bouncing through a separate lossy type.
+///
+/// Enable the `serde` feature to round-trip a `Point` to JSON or a
+/// binary save format.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Point<T: Number = i32> {
+    pub hx: T,
+    pub hy: T
 }
 
 /// Define a reference point for all others: (0, 0)
@@ -43,16 +54,16 @@ pub const UNIT:[Point; 6] = [
     Point { hx: -1, hy: 0 }    // direction 5
 ];
 
-impl Add for Point {
+impl<T: Number> Add for Point<T> {
     type Output = Self;
-    
+
     /// the sum of two points is just sum of both components
     fn add(self, other: Self) -> Self {
 	Self { hx: self.hx + other.hx, hy: self.hy + other.hy }
     }
 }
 
-impl Sub for Point {
+impl<T: Number> Sub for Point<T> {
     type Output = Self;
     /// the diff of two points is just diff of both components
     fn sub(self, rhs: Self) -> Self {
@@ -60,32 +71,178 @@ impl Sub for Point {
     }
 }
 
-impl Mul<i32> for Point {
+impl<T: Number> Mul<T> for Point<T> {
     type Output = Self;
     /// Multiply a hex vector by a scalar
-    fn mul(self, rhs: i32) -> Self {
+    fn mul(self, rhs: T) -> Self {
 	Self { hx: self.hx * rhs, hy: self.hy * rhs }
     }
 }
 
-impl Point {
+impl<T: Number> Div<T> for Point<T> {
+    type Output = Self;
+    /// Divide a hex vector by a scalar, e.g. to scale down a ring
+    fn div(self, rhs: T) -> Self {
+	Self { hx: self.hx / rhs, hy: self.hy / rhs }
+    }
+}
+
+impl<T: Number> AddAssign for Point<T> {
+    fn add_assign(&mut self, other: Self) {
+	self.hx = self.hx + other.hx;
+	self.hy = self.hy + other.hy;
+    }
+}
+
+impl<T: Number> SubAssign for Point<T> {
+    fn sub_assign(&mut self, other: Self) {
+	self.hx = self.hx - other.hx;
+	self.hy = self.hy - other.hy;
+    }
+}
+
+impl<T: Number> MulAssign<T> for Point<T> {
+    fn mul_assign(&mut self, rhs: T) {
+	self.hx = self.hx * rhs;
+	self.hy = self.hy * rhs;
+    }
+}
+
+impl<T: Number> Neg for Point<T> {
+    type Output = Self;
+    /// Get a vector pointing in the opposite direction
+    fn neg(self) -> Self {
+	Self { hx: -self.hx, hy: -self.hy }
+    }
+}
+
+impl<T: Number> From<(T, T)> for Point<T> {
+    fn from(t: (T, T)) -> Point<T> {
+	Point { hx: t.0, hy: t.1 }
+    }
+}
+
+impl<T: Number> From<Point<T>> for (T, T) {
+    fn from(p: Point<T>) -> (T, T) {
+	(p.hx, p.hy)
+    }
+}
+
+impl<T: Number> From<[T; 2]> for Point<T> {
+    fn from(a: [T; 2]) -> Point<T> {
+	Point { hx: a[0], hy: a[1] }
+    }
+}
+
+impl<T: Number + CoordNum> Point<T> {
 
     /// The third axis location is dependent on the other two:
     ///   hx = hy - hx
-    pub fn hz(&self) -> i32 {
+    pub fn hz(&self) -> T {
 	self.hy - self.hx
     }
 
-    pub fn neighbor(&self, direction: i32) -> Point {
-	*self + UNIT[(direction.rem_euclid(6)) as usize]
-    }
-    
     /// Get a vector pointing in the opposite direction
-    pub fn invert(&self) -> Point {
-	Point { hx: self.hx * -1, hy: self.hy * -1 }
+    #[deprecated(note = "use unary `-` (Neg) instead")]
+    pub fn invert(&self) -> Point<T> {
+	-*self
+    }
+
+    /// Dot product of the two hex vectors, over their cube components.
+    /// Useful for measuring the angle between two directions.
+    ///
+    /// Note: this uses the zero-sum cube z (`-(hx+hy)`, the same one
+    /// `rotate_around`/`distance` use), not `hz()` (`hy - hx`). `hz()`'s
+    /// components don't sum to zero, so a dot product built from it
+    /// isn't rotation-invariant.
+    pub fn dot(&self, other: &Point<T>) -> T {
+	let sz = -(self.hx + self.hy);
+	let oz = -(other.hx + other.hy);
+	self.hx * other.hx + self.hy * other.hy + sz * oz
+    }
+
+    /// Cross product (z-component) of the two hex vectors. The sign
+    /// tells whether `other` is a clockwise or counter-clockwise turn
+    /// away from `self`.
+    pub fn cross(&self, other: &Point<T>) -> T {
+	self.hx * other.hy - self.hy * other.hx
+    }
+
+    /// Distance is the maximum of the differences of the axes, but
+    /// because they are related by subtraction you can
+    /// just add the three and divide by 2.
+    /// See: [Axial Distance](https://www.redblobgames.com/grids/hexagons/#distances-axial)
+    pub fn distance(&self, other: &Point<T>) -> T {
+	let diff = *self - *other;
+	(diff.hx.abs() + (diff.hx + diff.hy).abs() + diff.hy.abs()) / T::from_isize(2)
+    }
+
+    // find the delta for a single hex in a line
+    // (dx / n+1, dy / n+1)
+    fn slope(&self, other: &Point<T>) -> Coord<T> {
+	let length = self.distance(other);
+	let diff = *other - *self;
+	Coord { x: diff.hx / length, y: diff.hy / length }
+    }
+
+    /// Convert to a `Point<f32>`, e.g. to do fractional line/ray math
+    /// on an otherwise-integer hex.
+    pub fn to_f32_point(&self) -> Point<f32> {
+	Point { hx: self.hx.to_f32(), hy: self.hy.to_f32() }
+    }
+}
+
+/// Snap a fractional axial coordinate `(qf, rf)` to the nearest integer
+/// hex using cube rounding.
+///
+/// Rounding `qf` and `rf` independently can land off the true hex (the
+/// rounded cube components no longer sum to zero). Instead round all
+/// three cube components `(qf, rf, sf = -(qf + rf))` and discard
+/// whichever rounded component has the largest error, recomputing it
+/// from the other two so that `q + r + s == 0` still holds.
+pub fn cube_round(qf: f32, rf: f32) -> Point<i32> {
+    let sf = -(qf + rf);
+
+    let mut rq = qf.round();
+    let mut rr = rf.round();
+    let rs = sf.round();
+
+    let q_diff = (rq - qf).abs();
+    let r_diff = (rr - rf).abs();
+    let s_diff = (rs - sf).abs();
+
+    if q_diff > r_diff && q_diff > s_diff {
+	rq = -rr - rs;
+    } else if r_diff > s_diff {
+	rr = -rq - rs;
+    }
+
+    Point { hx: rq as i32, hy: rr as i32 }
+}
+
+impl Point<f32> {
+    /// Snap to the nearest integer hex using cube rounding; see
+    /// `cube_round`.
+    pub fn round(&self) -> Point<i32> {
+	cube_round(self.hx, self.hy)
+    }
+}
+
+// The remaining hex-grid operations (direction numbers, rings, regions,
+// line drawing) only make sense on whole hexes, so they stay on the
+// default `Point<i32>` rather than joining the generic `Number` impl
+// above.
+impl Point<i32> {
+
+    pub fn neighbor(&self, direction: Direction) -> Point {
+	*self + direction.to_point()
     }
 
     /// reflect around one axis. Invert the other two axes
+    ///
+    /// `axis` picks one of the *three* cube axes (`rem_euclid(3)`), not
+    /// one of the *six* `Direction`s, so `Direction` isn't the right
+    /// type here even though `neighbor` takes one.
     pub fn reflect(&self, axis: i32 ) -> Point {
 	match axis.rem_euclid(3) {
 	    0 => Point { hx: self.hx, hy: self.hz() },
@@ -100,13 +257,16 @@ impl Point {
     }
 
     pub fn reflect_hy(&self) -> Point {
-	Point { hx: self.hz(), hy: self.hy }	
+	Point { hx: self.hz(), hy: self.hy }
     }
 
     pub fn reflect_hz(&self) -> Point {
 	Point { hx: self.hy, hy:  self.hx }
     }
-    
+
+    /// `hextant` is a rotation *amount* (like `rotate_around`'s `steps`),
+    /// not a `Direction` facing, so it stays an `i32` even though
+    /// `neighbor` was converted.
     pub fn rotate(&self, hextant: i32) -> Point {
 	// reduce the rotation to one full cycle at most
 	let rot = hextant.rem_euclid(3) as usize;
@@ -116,35 +276,54 @@ impl Point {
 	Point { hx: ring[rot] * invert, hy: ring[rot+1] * invert }
     }
 
-    /// Distance is the maximum of the differences of the axes, but
-    /// because they are related by subtraction you can
-    /// just add the three and divide by 2.
-    /// See: [Axial Distance](https://www.redblobgames.com/grids/hexagons/#distances-axial)
-    pub fn distance(&self, other: &Point) -> i32 {
-	let diff = *self - *other;
-	(diff.hx.abs() + (diff.hx + diff.hy).abs() + diff.hy.abs()) / 2
-    }
+    /// Rotate `self` around `center` by `steps` full 60° increments.
+    ///
+    /// Unlike `rotate`, which takes its third axis from `hz()`
+    /// (`hy - hx`), this uses the zero-sum cube z and repeatedly applies
+    /// the cube rotation rule: one clockwise step maps
+    /// `(x, y, z) -> (-z, -x, -y)`.
+    ///
+    /// This was requested alongside a six-variant `Direction` enum, but
+    /// `direction.rs` already has `Direction(u8)` (from the earlier,
+    /// overlapping direction/angle request) exposing the same
+    /// `to_point`/`from_point`/`rotate_cw`/`rotate_ccw`/`opposite` API
+    /// over the same `UNIT` array, so that's reused here rather than
+    /// introducing a second, redundant `Direction` type.
+    pub fn rotate_around(&self, center: &Point, steps: i32) -> Point {
+	let rel = *self - *center;
+	// Note: this is the zero-sum cube z (`-(hx+hy)`) used by `distance`,
+	// not `Point::hz()` (`hy - hx`), which is a different axis
+	// convention used by `reflect`/`rotate`. Using `hz()` here would
+	// only agree with this when `hy == 0`.
+	let mut cube = (rel.hx, rel.hy, -(rel.hx + rel.hy));
 
-    // find the delta for a single hex in a line
-    // (dx / n+1, dy / n+1)
-    fn slope(&self, other: &Point) -> Coord<f32> {
-	let length = self.distance(other) as f32;
-	let diff = *other - *self;
-	Coord { x: diff.hx as f32 / length, y: diff.hy as f32 / length }
+	for _ in 0..steps.rem_euclid(6) {
+	    let (x, y, z) = cube;
+	    cube = (-z, -x, -y);
+	}
+
+	*center + Point { hx: cube.0, hy: cube.1 }
     }
 
     // find a hex along the line
+    //
+    // Lerping hx/hy independently and rounding each with `.round()` can
+    // drift off the true hex line near ties, so do the lerp in
+    // `Point<f32>` and snap it back with cube rounding instead. Nudge
+    // the start endpoint by 1e-6 (once, not every sample) so
+    // exact-midpoint ties break the same way at every step.
     fn interpolate(&self, other: &Point, step: i32) -> Point {
-	let slope = self.slope(other);
-	Point {
-	    hx: ((self.hx as f32 + (step as f32 * slope.x).round()) as i32),
-	    hy: ((self.hy as f32 + (step as f32 * slope.y).round()) as i32)
-	}
+	let start = self.to_f32_point() + Point { hx: 1e-6, hy: 1e-6 };
+	let slope = start.slope(&other.to_f32_point());
+	Point::<f32> {
+	    hx: start.hx + (step as f32 * slope.x),
+	    hy: start.hy + (step as f32 * slope.y)
+	}.round()
     }
 
-    pub fn line(&self, other: &Point) -> Vec<Point> {	
+    pub fn line(&self, other: &Point) -> Vec<Point> {
 	(0..self.distance(other)+1).map( | i |
-	    self.interpolate(&other, i)
+	    self.interpolate(other, i)
 	).collect()
     }
 
@@ -154,7 +333,7 @@ impl Point {
 
 	for hx in -dist..dist+1 {
 	    for hy in (-dist).max(-hx - dist)..(dist.min(-hx+dist))+1 {
-		range.push(*self + Point { hx: hx, hy: hy });
+		range.push(*self + Point { hx, hy });
 	    }
 	}
 	range
@@ -168,13 +347,28 @@ impl Point {
 
 	let mut next = UNIT[4] * radius.abs();
 	let mut ring = vec!();
-	
-	for hextant in 0..6 {
+
+	for hextant in 0..6u8 {
 	    for _step in 0..radius {
 		ring.push(next);
-		next = next.neighbor(hextant); 
+		next = next.neighbor(Direction::new(hextant));
 	    }
 	}
 	ring
     }
+
+    /// Walk outward from `self`, yielding the center followed by every
+    /// hex in ring 1, ring 2, ... ring `radius`, in contiguous ring
+    /// order. Useful for rendering and range-flood code that wants to
+    /// draw from the center outward and can stop early at a partial
+    /// radius, unlike `region`'s arbitrary row order.
+    pub fn spiral(&self, radius: i32) -> Vec<Point> {
+	let mut cells = vec!(*self);
+
+	for r in 1..=radius {
+	    cells.extend(ORIGIN.ring(r).into_iter().map(|p| *self + p));
+	}
+
+	cells
+    }
 }
@@ -0,0 +1,19 @@
+
+
+#[cfg(test)]
+mod tests {
+    use hex::direction::Direction;
+    use hex::point::{ORIGIN, UNIT};
+
+    // test_direction_to()
+    #[test]
+    fn test_direction_to() {
+	for i in 0..6u8 {
+	    assert_eq!(ORIGIN.direction_to(&UNIT[i as usize]), Direction::new(i));
+
+	    // also holds once the pair is translated off the origin
+	    let base = UNIT[((i + 2) % 6) as usize];
+	    assert_eq!(base.direction_to(&(base + UNIT[i as usize])), Direction::new(i));
+	}
+    }
+}
@@ -0,0 +1,37 @@
+
+#[cfg(test)]
+mod tests {
+    use hex::grid::{Grid, HashGrid};
+    use hex::hex::Hex;
+    use hex::point::{Point, ORIGIN, UNIT};
+
+    // test_insert_get_len()
+    #[test]
+    fn test_insert_get_len() {
+	let mut grid = HashGrid::default();
+	assert!(grid.is_empty());
+	assert_eq!(grid.len(), 0);
+	assert_eq!(grid.get(&ORIGIN), None);
+
+	grid.insert(ORIGIN, Hex::new(ORIGIN));
+	assert!(!grid.is_empty());
+	assert_eq!(grid.len(), 1);
+	assert_eq!(grid.get(&ORIGIN), Some(&Hex::new(ORIGIN)));
+    }
+
+    // test_neighbors()
+    #[test]
+    fn test_neighbors() {
+	// a grid holding the origin and only two of its six neighbors
+	let grid: HashGrid = [
+	    (ORIGIN, Hex::new(ORIGIN)),
+	    (UNIT[0], Hex::new(UNIT[0])),
+	    (UNIT[3], Hex::new(UNIT[3]))
+	].into_iter().collect();
+
+	assert_eq!(grid.neighbors(&ORIGIN).len(), 2);
+
+	// a position with no neighbors present yields an empty list
+	assert_eq!(grid.neighbors(&Point { hx: 100, hy: 100 }).len(), 0);
+    }
+}
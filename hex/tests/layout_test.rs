@@ -0,0 +1,51 @@
+
+#[cfg(test)]
+mod tests {
+    use geo::geometry::Coord;
+    use hex::layout::Layout;
+    use hex::map::Orientation;
+    use hex::point::{Point, ORIGIN, UNIT};
+
+    // test_hex_to_pixel_and_back()
+    #[test]
+    fn test_hex_to_pixel_and_back() {
+	for orientation in [Orientation::Vertical, Orientation::Horizontal] {
+	    let layout = Layout::new(
+		orientation,
+		Coord { x: 10.0, y: 10.0 },
+		Coord { x: 0.0, y: 0.0 }
+	    );
+
+	    let points: Vec<Point> = std::iter::once(ORIGIN)
+		.chain(UNIT.iter().copied())
+		.collect();
+
+	    for p in points {
+		let pixel = layout.hex_to_pixel(&p);
+		assert_eq!(layout.pixel_to_hex(pixel), p);
+	    }
+	}
+    }
+
+    // test_polygon_corners()
+    #[test]
+    fn test_polygon_corners() {
+	let layout = Layout::new(
+	    Orientation::Vertical,
+	    Coord { x: 10.0, y: 10.0 },
+	    Coord { x: 0.0, y: 0.0 }
+	);
+
+	let center = layout.hex_to_pixel(&ORIGIN);
+	let corners = layout.polygon_corners(&ORIGIN);
+
+	assert_eq!(corners.len(), 6);
+
+	// each corner is one hex size away from the center
+	for corner in corners {
+	    let dx = corner.x - center.x;
+	    let dy = corner.y - center.y;
+	    assert!(((dx * dx + dy * dy).sqrt() - 10.0).abs() < 1e-3);
+	}
+    }
+}
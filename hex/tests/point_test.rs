@@ -1,6 +1,7 @@
 
  
 #[cfg(test)]
+#[allow(deprecated)]
 mod tests {
     use hex::point::*;
 
@@ -57,8 +58,8 @@ mod tests {
     // test_neighbor
     #[test]
     fn test_neighbor() {
-	for i in 0..6 {
-	    assert_eq!(ORIGIN.neighbor(i), UNIT[i as usize])
+	for i in 0..6u8 {
+	    assert_eq!(ORIGIN.neighbor(hex::direction::Direction::new(i)), UNIT[i as usize])
 	}
     }
     
@@ -73,20 +74,70 @@ mod tests {
 	assert_eq!( first, first.rotate(6));
 	assert_eq!( first, first.rotate(-6));
 	
-	assert_eq!( Point { hx: first.hy * -1, hy: first.hz() * -1 }, first.rotate(1));
-	assert_eq!( Point { hx: first.hy * -1, hy: first.hz() * -1 }, first.rotate(7));
-	assert_eq!( Point { hx: first.hz() * -1, hy: first.hx * -1 }, first.rotate(-1));
-	assert_eq!( Point { hx: first.hz() * -1, hy: first.hx * -1 }, first.rotate(-7));
+	assert_eq!( Point { hx: -first.hy, hy: -first.hz() }, first.rotate(1));
+	assert_eq!( Point { hx: -first.hy, hy: -first.hz() }, first.rotate(7));
+	assert_eq!( Point { hx: -first.hz(), hy: -first.hx }, first.rotate(-1));
+	assert_eq!( Point { hx: -first.hz(), hy: -first.hx }, first.rotate(-7));
 
 	assert_eq!( Point { hx: first.hz(), hy: first.hx }, first.rotate(2));
-	assert_eq!( Point { hx: first.hx * -1, hy: first.hy * -1 }, first.rotate(3));
+	assert_eq!( Point { hx: -first.hx, hy: -first.hy }, first.rotate(3));
 	assert_eq!( first.invert(), first.rotate(3));
 	assert_eq!( first.invert(), first.rotate(-3));
 
-	assert_eq!( Point { hx: first.hz() * -1, hy: first.hx * -1 }, first.rotate(5));
+	assert_eq!( Point { hx: -first.hz(), hy: -first.hx }, first.rotate(5));
 
     }
 
+    // test_rotate_around()
+    #[test]
+    fn test_rotate_around() {
+	// an off-axis point (neither hx nor hy is zero), so a wrong cube
+	// z-component would actually show up
+	let first = Point { hx: 1, hy: 1 };
+
+	assert_eq!(first.rotate_around(&ORIGIN, 1), Point { hx: 2, hy: -1 });
+	assert_eq!(first.rotate_around(&ORIGIN, 0), first);
+	assert_eq!(first.rotate_around(&ORIGIN, 6), first);
+
+	// distance from the center must be invariant under every 60-degree step
+	for steps in 0..6 {
+	    let rotated = first.rotate_around(&ORIGIN, steps);
+	    assert_eq!(ORIGIN.distance(&rotated), ORIGIN.distance(&first));
+	}
+    }
+
+    // test_dot()
+    #[test]
+    fn test_dot() {
+	// a unit vector dotted with itself is always 2, regardless of which
+	// of the six directions it is -- rotating self.dot(self) must
+	// leave it invariant
+	for unit in &UNIT {
+	    assert_eq!(unit.dot(unit), 2);
+	}
+
+	for steps in 0..6 {
+	    let rotated = UNIT[1].rotate_around(&ORIGIN, steps);
+	    assert_eq!(rotated.dot(&rotated), UNIT[1].dot(&UNIT[1]));
+	}
+    }
+
+    // test_cube_round()
+    #[test]
+    fn test_cube_round() {
+	// exact integers round to themselves
+	assert_eq!(cube_round(3.0, -2.0), Point { hx: 3, hy: -2 });
+
+	// a small fractional nudge in each axis still lands on the same hex
+	assert_eq!(cube_round(3.1, -2.1), Point { hx: 3, hy: -2 });
+	assert_eq!(cube_round(2.9, -1.9), Point { hx: 3, hy: -2 });
+
+	// rounding q, r, and s independently here would give (1, 1, -1),
+	// which doesn't sum to zero; q and r tie for the largest error, the
+	// tie goes to r, and r is recomputed from q and s instead
+	assert_eq!(cube_round(0.6, 0.6), Point { hx: 1, hy: 0 });
+    }
+
     // test_add()
     #[test]
     fn test_add() {
@@ -200,9 +251,9 @@ mod tests {
     #[test]
     fn test_line() {
 	// test interpolating the origin and units
-	for h in 0..5 {
-	    let expect: Vec<Point> = vec!(ORIGIN, UNIT[h]);
-	    let actual = ORIGIN.line(&UNIT[h]);
+	for unit in UNIT.iter().take(5) {
+	    let expect: Vec<Point> = vec!(ORIGIN, *unit);
+	    let actual = ORIGIN.line(unit);
 	    assert_eq!(expect, actual);
 	}
 